@@ -0,0 +1,97 @@
+use chrono::{DateTime, NaiveDate, ParseError, Utc};
+
+use crate::GitCommit;
+
+/// Inclusive `--since`/`--until` bound for a `run_forora` run, parsed from
+/// `YYYY-MM-DD` strings. `run_forora` is expected to skip any commit that
+/// falls outside the range before it reaches `GitStat::process`, so every
+/// collector is scope-aware without changes of its own.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CommitRange {
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+}
+
+impl CommitRange {
+    pub fn new(since: Option<&str>, until: Option<&str>) -> Result<Self, ParseError> {
+        Ok(Self {
+            since: since.map(parse_day_start).transpose()?,
+            until: until.map(parse_day_end).transpose()?,
+        })
+    }
+
+    pub fn contains(&self, commit: &GitCommit) -> bool {
+        if let Some(since) = self.since {
+            if commit.date < since {
+                return false;
+            }
+        }
+        if let Some(until) = self.until {
+            if commit.date > until {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+fn parse_day_start(value: &str) -> Result<DateTime<Utc>, ParseError> {
+    let date = NaiveDate::parse_from_str(value, "%Y-%m-%d")?;
+    Ok(date.and_hms_opt(0, 0, 0).unwrap().and_utc())
+}
+
+fn parse_day_end(value: &str) -> Result<DateTime<Utc>, ParseError> {
+    let date = NaiveDate::parse_from_str(value, "%Y-%m-%d")?;
+    Ok(date.and_hms_opt(23, 59, 59).unwrap().and_utc())
+}
+
+// NOTE: branch selection is a separate concern from this date range —
+// `run_forora` (crate root) turns `RunFororaOptions::branches` into the set
+// of `git2::Revwalk` roots it pushes, independently of the `CommitRange`
+// built from `since`/`until` here. Only the date range is applied
+// per-commit, by `process::process_commit`, which takes an optional
+// `&CommitRange` and applies `contains` before any commit reaches a
+// collector.
+
+#[cfg(test)]
+mod commit_range_tests {
+    use chrono::{TimeZone, Utc};
+    use crate::GitCommit;
+    use crate::commit_range::CommitRange;
+
+    fn commit_on(year: i32, month: u32, day: u32) -> GitCommit {
+        let mut commit = GitCommit::default();
+        commit.date = Utc.with_ymd_and_hms(year, month, day, 12, 0, 0).unwrap();
+        commit
+    }
+
+    #[test]
+    fn test_unbounded_range_contains_everything() {
+        let range = CommitRange::default();
+        assert!(range.contains(&commit_on(2020, 1, 1)));
+    }
+
+    #[test]
+    fn test_commit_before_since_is_excluded() {
+        let range = CommitRange::new(Some("2024-01-01"), None).unwrap();
+        assert!(!range.contains(&commit_on(2023, 12, 31)));
+    }
+
+    #[test]
+    fn test_commit_after_until_is_excluded() {
+        let range = CommitRange::new(None, Some("2024-01-01")).unwrap();
+        assert!(!range.contains(&commit_on(2024, 1, 2)));
+    }
+
+    #[test]
+    fn test_commit_inside_range_is_included() {
+        let range = CommitRange::new(Some("2024-01-01"), Some("2024-01-31")).unwrap();
+        assert!(range.contains(&commit_on(2024, 1, 15)));
+    }
+
+    #[test]
+    fn test_commit_on_boundary_days_is_included() {
+        let range = CommitRange::new(Some("2024-01-01"), Some("2024-01-31")).unwrap();
+        assert!(range.contains(&commit_on(2024, 1, 31)));
+    }
+}
@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+/// Tracks how often unordered pairs of files change together across
+/// commits, so `SimilarFilesChangingCollector` can surface hidden coupling.
+/// Commits touching more than `max_files_per_commit` files are ignored —
+/// a single huge commit otherwise drowns out every real pairing with noise.
+pub struct DuplicateDetector {
+    max_files_per_commit: usize,
+    co_change_counts: HashMap<(String, String), i32>,
+    file_commit_counts: HashMap<String, i32>,
+    skipped_commits: i32,
+}
+
+impl DuplicateDetector {
+    pub fn new(max_files_per_commit: usize) -> Self {
+        Self {
+            max_files_per_commit,
+            co_change_counts: HashMap::new(),
+            file_commit_counts: HashMap::new(),
+            skipped_commits: 0,
+        }
+    }
+
+    /// Number of commits `add` has dropped so far for touching more than
+    /// `max_files_per_commit` files.
+    pub fn skipped_commits(&self) -> i32 {
+        self.skipped_commits
+    }
+
+    pub fn add(&mut self, files: Vec<&str>) {
+        let mut unique_files: Vec<&str> = files;
+        unique_files.sort();
+        unique_files.dedup();
+
+        if unique_files.is_empty() {
+            return;
+        }
+
+        if unique_files.len() > self.max_files_per_commit {
+            self.skipped_commits += 1;
+            return;
+        }
+
+        for file in &unique_files {
+            *self.file_commit_counts.entry(file.to_string()).or_insert(0) += 1;
+        }
+
+        for i in 0..unique_files.len() {
+            for j in (i + 1)..unique_files.len() {
+                let key = pair_key(unique_files[i], unique_files[j]);
+                *self.co_change_counts.entry(key).or_insert(0) += 1;
+            }
+        }
+    }
+
+    /// Top `limit` file pairs by co-change count (descending), excluding
+    /// pairs below `min_support`. Each result is
+    /// `(file_a, file_b, co_changes, confidence)`, where `confidence` is
+    /// `co_changes / commits touching the more-frequently-changed file`.
+    pub fn top_pairs(&self, limit: usize, min_support: i32) -> Vec<(String, String, i32, f64)> {
+        let mut pairs: Vec<(String, String, i32, f64)> = self.co_change_counts.iter()
+            .filter(|(_, count)| **count >= min_support)
+            .map(|((file_a, file_b), count)| {
+                let more_frequent = self.file_commit_counts.get(file_a).copied().unwrap_or(0)
+                    .max(self.file_commit_counts.get(file_b).copied().unwrap_or(0));
+                let confidence = if more_frequent > 0 {
+                    *count as f64 / more_frequent as f64
+                } else {
+                    0.0
+                };
+                (file_a.clone(), file_b.clone(), *count, confidence)
+            })
+            .collect();
+
+        pairs.sort_by(|a, b| b.2.cmp(&a.2).then_with(|| a.0.cmp(&b.0)).then_with(|| a.1.cmp(&b.1)));
+        pairs.truncate(limit);
+        pairs
+    }
+}
+
+fn pair_key(file_a: &str, file_b: &str) -> (String, String) {
+    if file_a < file_b {
+        (file_a.to_string(), file_b.to_string())
+    } else {
+        (file_b.to_string(), file_a.to_string())
+    }
+}
+
+#[cfg(test)]
+mod duplicate_detector_tests {
+    use crate::duplicates::DuplicateDetector;
+
+    #[test]
+    fn test_pair_with_no_co_changes_is_not_reported() {
+        let mut detector = DuplicateDetector::new(10);
+        detector.add(vec!["a.rs"]);
+        detector.add(vec!["b.rs"]);
+
+        assert!(detector.top_pairs(10, 1).is_empty());
+    }
+
+    #[test]
+    fn test_pair_changing_together_is_counted_regardless_of_order() {
+        let mut detector = DuplicateDetector::new(10);
+        detector.add(vec!["a.rs", "b.rs"]);
+        detector.add(vec!["b.rs", "a.rs"]);
+
+        let pairs = detector.top_pairs(10, 1);
+
+        assert_eq!(pairs, vec![("a.rs".to_string(), "b.rs".to_string(), 2, 1.0)]);
+    }
+
+    #[test]
+    fn test_confidence_is_relative_to_the_more_frequent_file() {
+        let mut detector = DuplicateDetector::new(10);
+        detector.add(vec!["a.rs", "b.rs"]);
+        detector.add(vec!["a.rs"]);
+        detector.add(vec!["a.rs"]);
+
+        let pairs = detector.top_pairs(10, 1);
+
+        // a.rs changed 3 times, b.rs changed once, they co-changed once.
+        assert_eq!(pairs, vec![("a.rs".to_string(), "b.rs".to_string(), 1, 1.0 / 3.0)]);
+    }
+
+    #[test]
+    fn test_min_support_filters_out_weak_pairs() {
+        let mut detector = DuplicateDetector::new(10);
+        detector.add(vec!["a.rs", "b.rs"]);
+
+        assert!(detector.top_pairs(10, 2).is_empty());
+    }
+
+    #[test]
+    fn test_commits_touching_too_many_files_are_ignored() {
+        let mut detector = DuplicateDetector::new(2);
+        detector.add(vec!["a.rs", "b.rs", "c.rs"]);
+
+        assert!(detector.top_pairs(10, 1).is_empty());
+    }
+
+    #[test]
+    fn test_commits_touching_too_many_files_are_counted_as_skipped() {
+        let mut detector = DuplicateDetector::new(2);
+        detector.add(vec!["a.rs", "b.rs", "c.rs"]);
+        detector.add(vec!["a.rs", "b.rs"]);
+
+        assert_eq!(detector.skipped_commits(), 1);
+    }
+
+    #[test]
+    fn test_top_pairs_is_limited_and_sorted_by_count_descending() {
+        let mut detector = DuplicateDetector::new(10);
+        detector.add(vec!["a.rs", "b.rs"]);
+        detector.add(vec!["a.rs", "b.rs"]);
+        detector.add(vec!["c.rs", "d.rs"]);
+
+        let pairs = detector.top_pairs(1, 1);
+
+        assert_eq!(pairs, vec![("a.rs".to_string(), "b.rs".to_string(), 2, 1.0)]);
+    }
+}
@@ -0,0 +1,72 @@
+use chrono::NaiveDate;
+
+/// Number of discrete shades a day's activity is quantized into (0..=4),
+/// matching the familiar GitHub-style contribution calendar.
+pub const INTENSITY_LEVELS: u8 = 5;
+
+/// Color scheme `HtmlReporter` paints the heatmap grid with. The collector
+/// itself only produces counts; the scheme is a rendering choice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorScheme {
+    #[default]
+    Green,
+    Blue,
+}
+
+/// One cell of the contribution calendar: a calendar day, where it sits in
+/// the ISO-week grid, and its quantized intensity relative to the busiest
+/// day in the range.
+#[derive(Debug, Clone)]
+pub struct HeatmapDay {
+    pub date: NaiveDate,
+    pub iso_year: i32,
+    pub iso_week: u32,
+    /// Monday-based weekday (0 = Monday .. 6 = Sunday), matching
+    /// `iso_week`'s own Monday-based ISO 8601 convention so a day's row and
+    /// column agree on where a week starts.
+    pub weekday: u32,
+    pub commit_count: i32,
+    pub net_lines: i64,
+    pub intensity: u8,
+}
+
+/// Buckets `count` into `0..=INTENSITY_LEVELS - 1` relative to `max_count`.
+/// A day with no commits is always level 0.
+pub fn quantize_intensity(count: i32, max_count: i32) -> u8 {
+    if max_count <= 0 || count <= 0 {
+        return 0;
+    }
+
+    let ratio = count as f64 / max_count as f64;
+    (ratio * (INTENSITY_LEVELS - 1) as f64).ceil() as u8
+}
+
+#[cfg(test)]
+mod quantize_intensity_tests {
+    use crate::heatmap::quantize_intensity;
+
+    #[test]
+    fn test_zero_count_is_level_zero() {
+        assert_eq!(quantize_intensity(0, 10), 0);
+    }
+
+    #[test]
+    fn test_zero_max_count_is_level_zero() {
+        assert_eq!(quantize_intensity(5, 0), 0);
+    }
+
+    #[test]
+    fn test_busiest_day_is_top_level() {
+        assert_eq!(quantize_intensity(10, 10), 4);
+    }
+
+    #[test]
+    fn test_quiet_day_gets_low_but_nonzero_level() {
+        assert_eq!(quantize_intensity(1, 10), 1);
+    }
+
+    #[test]
+    fn test_mid_range_day_gets_a_mid_level() {
+        assert_eq!(quantize_intensity(5, 10), 2);
+    }
+}
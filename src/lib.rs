@@ -0,0 +1,210 @@
+use std::path::Path;
+
+use chrono::{DateTime, Datelike, Timelike, Utc};
+
+pub mod collectors;
+pub mod commit_range;
+pub mod duplicates;
+pub mod heatmap;
+pub mod mailmap;
+pub mod models;
+pub mod process;
+pub mod reporter;
+pub mod stats;
+pub mod viewmodel;
+
+pub use commit_range::CommitRange;
+pub use mailmap::Mailmap;
+pub use reporter::{BufferedOutput, HtmlReporter, Reporter};
+pub use viewmodel::GitStatsViewModel;
+
+use crate::collectors::create_stat_collectors;
+use crate::models::LineStat;
+use crate::process::process_commit;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileOperationKind {
+    Added,
+    Modified,
+    Deleted,
+    Renamed,
+}
+
+#[derive(Debug, Clone)]
+pub struct FileOperation {
+    pub file: String,
+    pub file_extension: String,
+    pub kind: FileOperationKind,
+}
+
+/// A single commit as the collectors from `create_stat_collectors` see it.
+#[derive(Debug, Clone)]
+pub struct GitCommit {
+    pub commit_hash: String,
+    pub author: String,
+    pub date: DateTime<Utc>,
+    pub message: String,
+    pub line_stats: Vec<LineStat>,
+    pub file_operations: Vec<FileOperation>,
+}
+
+impl Default for GitCommit {
+    fn default() -> Self {
+        Self {
+            commit_hash: String::new(),
+            author: String::new(),
+            date: DateTime::<Utc>::from_timestamp(0, 0).unwrap(),
+            message: String::new(),
+            line_stats: Vec::new(),
+            file_operations: Vec::new(),
+        }
+    }
+}
+
+impl GitCommit {
+    pub fn total_lines_added(&self) -> i32 {
+        self.line_stats.iter().map(|line| line.lines_added).sum()
+    }
+
+    pub fn total_lines_deleted(&self) -> i32 {
+        self.line_stats.iter().map(|line| line.lines_deleted).sum()
+    }
+
+    pub fn total_files_added(&self) -> i32 {
+        self.count_file_operations(FileOperationKind::Added)
+    }
+
+    pub fn total_files_deleted(&self) -> i32 {
+        self.count_file_operations(FileOperationKind::Deleted)
+    }
+
+    pub fn total_files_modified(&self) -> i32 {
+        self.count_file_operations(FileOperationKind::Modified)
+    }
+
+    pub fn total_files_renamed(&self) -> i32 {
+        self.count_file_operations(FileOperationKind::Renamed)
+    }
+
+    fn count_file_operations(&self, kind: FileOperationKind) -> i32 {
+        self.file_operations.iter().filter(|op| op.kind == kind).count() as i32
+    }
+
+    pub fn total_message_lines(&self) -> i32 {
+        self.message.lines().count() as i32
+    }
+
+    pub fn total_message_size(&self) -> i32 {
+        self.message.len() as i32
+    }
+
+    pub fn day_key(&self) -> String {
+        self.date.format("%Y-%m-%d").to_string()
+    }
+
+    pub fn hour_key_by_weekday(&self) -> String {
+        format!("{}-{}", self.date.weekday().num_days_from_sunday(), self.date.hour())
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct LineStats {
+    pub added: i32,
+    pub deleted: i32,
+}
+
+pub trait GitStat {
+    fn process(&mut self, commit: &GitCommit);
+
+    /// Most collectors only ever append to `viewmodel`; give it a no-op
+    /// default so a `GitStat` that's purely diagnostic (or JSON-only, see
+    /// `stats::JsonValue`) doesn't need to implement it.
+    fn update(&self, _viewmodel: &mut GitStatsViewModel) {}
+}
+
+/// Options `run_forora` accepts beyond the repo path itself.
+#[derive(Debug, Clone, Default)]
+pub struct RunFororaOptions {
+    /// Inclusive `YYYY-MM-DD` lower bound; commits dated earlier are skipped.
+    pub since: Option<String>,
+    /// Inclusive `YYYY-MM-DD` upper bound; commits dated later are skipped.
+    pub until: Option<String>,
+    /// Branches (short ref names) whose history is walked. Empty means
+    /// "just `HEAD`".
+    pub branches: Vec<String>,
+    /// Author-identity mailmap. Defaults to the repo's own `.mailmap` when
+    /// not supplied.
+    pub mailmap: Option<Mailmap>,
+}
+
+/// Walks `repo_path` under `options`, feeding every commit in range through
+/// the collectors from `create_stat_collectors`, then renders the resulting
+/// `GitStatsViewModel` with `reporter` into `output`.
+pub fn run_forora(
+    repo_path: &Path,
+    output: &mut dyn std::io::Write,
+    reporter: Box<dyn Reporter>,
+    options: &RunFororaOptions,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let repo = git2::Repository::open(repo_path)?;
+
+    let range = CommitRange::new(options.since.as_deref(), options.until.as_deref())?;
+    let mailmap = options
+        .mailmap
+        .clone()
+        .unwrap_or_else(|| Mailmap::load_default(repo_path));
+
+    let mut revwalk = repo.revwalk()?;
+    revwalk.set_sorting(git2::Sort::TIME | git2::Sort::REVERSE)?;
+
+    if options.branches.is_empty() {
+        revwalk.push_head()?;
+    } else {
+        for branch in &options.branches {
+            let reference = repo.resolve_reference_from_short_name(branch)?;
+            let oid = reference.target().ok_or("branch has no target commit")?;
+            revwalk.push(oid)?;
+        }
+    }
+
+    let mut collectors = create_stat_collectors();
+
+    for oid in revwalk {
+        let commit = to_git_commit(&repo, oid?)?;
+        process_commit(&commit, &mut collectors, Some(&range), Some(&mailmap));
+    }
+
+    let mut viewmodel = GitStatsViewModel::default();
+    for collector in &collectors {
+        collector.update(&mut viewmodel);
+    }
+
+    reporter.render(&viewmodel, output)?;
+    Ok(())
+}
+
+// NOTE: this only fills in commit metadata (hash, author, date, message).
+// `line_stats`/`file_operations` need diffing each commit against its
+// parent tree, which is the same tree-diffing `git2` work the rest of this
+// checkout's collectors were written against but that this snapshot
+// doesn't include yet — those fields come back empty until that's wired
+// in, so collectors relying on them (lines/files-by-day, code size, etc.)
+// under-report against a real repo until then.
+fn to_git_commit(repo: &git2::Repository, oid: git2::Oid) -> Result<GitCommit, git2::Error> {
+    let commit = repo.find_commit(oid)?;
+    let author = commit.author();
+
+    Ok(GitCommit {
+        commit_hash: commit.id().to_string(),
+        author: format!(
+            "{} <{}>",
+            author.name().unwrap_or_default(),
+            author.email().unwrap_or_default()
+        ),
+        date: DateTime::<Utc>::from_timestamp(commit.time().seconds(), 0)
+            .unwrap_or_else(|| DateTime::<Utc>::from_timestamp(0, 0).unwrap()),
+        message: commit.message().unwrap_or_default().to_string(),
+        line_stats: Vec::new(),
+        file_operations: Vec::new(),
+    })
+}
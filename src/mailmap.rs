@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::GitCommit;
+
+/// Coalesces author identities using a `.mailmap`-style file, so that e.g.
+/// `Jane <jane@work>` and `Jane <jane@personal>` aggregate as a single
+/// author in the summary and per-author collectors instead of two.
+///
+/// Each non-empty, non-comment line is `Proper Name <canonical@email>
+/// <old@email>...`. Every email on the line — canonical and aliases alike —
+/// maps onto the same stable identity string (`Proper Name
+/// <canonical@email>`), so a commit made under any of those emails
+/// normalizes to the same value as one made under the canonical email.
+#[derive(Debug, Clone, Default)]
+pub struct Mailmap {
+    by_email: HashMap<String, String>,
+}
+
+impl Mailmap {
+    pub fn from_str(contents: &str) -> Self {
+        let mut by_email = HashMap::new();
+
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some((canonical_identity, emails)) = parse_mailmap_line(line) {
+                for email in emails {
+                    by_email.insert(email, canonical_identity.clone());
+                }
+            }
+        }
+
+        Self { by_email }
+    }
+
+    pub fn load(path: &Path) -> std::io::Result<Self> {
+        Ok(Self::from_str(&fs::read_to_string(path)?))
+    }
+
+    /// Loads `.mailmap` from the repo root if present, otherwise returns an
+    /// empty (no-op) mailmap.
+    pub fn load_default(repo_path: &Path) -> Self {
+        Self::load(&repo_path.join(".mailmap")).unwrap_or_default()
+    }
+
+    pub fn canonical_author(&self, author: &str) -> String {
+        match extract_email(author) {
+            Some(email) => self.by_email.get(&email).cloned().unwrap_or_else(|| author.to_string()),
+            None => author.to_string(),
+        }
+    }
+
+    pub fn normalize(&self, commit: &mut GitCommit) {
+        commit.author = self.canonical_author(&commit.author);
+    }
+}
+
+fn parse_mailmap_line(line: &str) -> Option<(String, Vec<String>)> {
+    let open = line.find('<')?;
+    let name = line[..open].trim().to_string();
+
+    let emails: Vec<String> = line[open..]
+        .split('<')
+        .filter_map(|part| part.split('>').next())
+        .map(|part| part.trim().to_string())
+        .filter(|part| !part.is_empty())
+        .collect();
+
+    let canonical_email = emails.first()?.clone();
+    let canonical_identity = format!("{} <{}>", name, canonical_email);
+
+    Some((canonical_identity, emails))
+}
+
+fn extract_email(author: &str) -> Option<String> {
+    let open = author.find('<')?;
+    let close = author[open..].find('>')?;
+    Some(author[open + 1..open + close].trim().to_string())
+}
+
+// NOTE: `process::process_commit` (this crate) calls `normalize` on every
+// commit, ahead of any `GitStat::process`, whenever it's given a mailmap.
+// `run_forora` (crate root) is what supplies that mailmap: it uses
+// `RunFororaOptions::mailmap` when set, otherwise falls back to
+// `Mailmap::load_default`.
+
+#[cfg(test)]
+mod mailmap_tests {
+    use crate::mailmap::Mailmap;
+
+    #[test]
+    fn test_alias_email_normalizes_to_canonical_identity() {
+        let mailmap = Mailmap::from_str("Jane Doe <jane@work> <jane@personal>");
+
+        assert_eq!(mailmap.canonical_author("Jane Doe <jane@personal>"), "Jane Doe <jane@work>");
+    }
+
+    #[test]
+    fn test_canonical_email_normalizes_to_the_same_identity_as_its_alias() {
+        let mailmap = Mailmap::from_str("Jane Doe <jane@work> <jane@personal>");
+
+        let canonical = mailmap.canonical_author("Jane Doe <jane@work>");
+        let alias = mailmap.canonical_author("Jane Doe <jane@personal>");
+
+        assert_eq!(canonical, alias);
+    }
+
+    #[test]
+    fn test_unknown_author_passes_through_unchanged() {
+        let mailmap = Mailmap::from_str("Jane Doe <jane@work> <jane@personal>");
+
+        assert_eq!(mailmap.canonical_author("Bob <bob@work>"), "Bob <bob@work>");
+    }
+
+    #[test]
+    fn test_comment_and_blank_lines_are_ignored() {
+        let mailmap = Mailmap::from_str("# comment\n\nJane Doe <jane@work> <jane@personal>\n");
+
+        assert_eq!(mailmap.canonical_author("Jane Doe <jane@personal>"), "Jane Doe <jane@work>");
+    }
+
+    #[test]
+    fn test_author_without_email_passes_through_unchanged() {
+        let mailmap = Mailmap::from_str("Jane Doe <jane@work> <jane@personal>");
+
+        assert_eq!(mailmap.canonical_author("Jane Doe"), "Jane Doe");
+    }
+}
@@ -0,0 +1,8 @@
+/// Per-commit line delta, as recorded by whatever populates `GitCommit`.
+/// `run_forora` does not diff commits, so the `GitCommit`s it builds carry
+/// no `LineStat`s.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LineStat {
+    pub lines_added: i32,
+    pub lines_deleted: i32,
+}
@@ -0,0 +1,99 @@
+use crate::commit_range::CommitRange;
+use crate::mailmap::Mailmap;
+use crate::{GitCommit, GitStat};
+
+/// The per-commit entry point `run_forora`'s repo walk feeds each commit
+/// through: applies the optional date-range scope, coalesces the author via
+/// the optional mailmap, then dispatches the (possibly normalized) commit to
+/// every collector. Returns `false` when the commit was skipped because it
+/// fell outside `range`.
+pub fn process_commit(
+    commit: &GitCommit,
+    collectors: &mut Vec<Box<dyn GitStat>>,
+    range: Option<&CommitRange>,
+    mailmap: Option<&Mailmap>,
+) -> bool {
+    if let Some(range) = range {
+        if !range.contains(commit) {
+            return false;
+        }
+    }
+
+    let mut commit = commit.clone();
+    if let Some(mailmap) = mailmap {
+        mailmap.normalize(&mut commit);
+    }
+
+    for collector in collectors.iter_mut() {
+        collector.process(&commit);
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod process_commit_tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use chrono::{TimeZone, Utc};
+    use crate::{GitCommit, GitStat, GitStatsViewModel};
+    use crate::commit_range::CommitRange;
+    use crate::mailmap::Mailmap;
+    use crate::process::process_commit;
+
+    struct CountingStat {
+        authors_seen: Rc<RefCell<Vec<String>>>,
+    }
+
+    impl GitStat for CountingStat {
+        fn process(&mut self, commit: &GitCommit) {
+            self.authors_seen.borrow_mut().push(commit.author.clone());
+        }
+
+        fn update(&self, _viewmodel: &mut GitStatsViewModel) {}
+    }
+
+    #[test]
+    fn test_commit_outside_range_is_skipped() {
+        let range = CommitRange::new(Some("2024-01-01"), None).unwrap();
+        let mut commit = GitCommit::default();
+        commit.date = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+
+        let authors_seen = Rc::new(RefCell::new(Vec::new()));
+        let mut collectors: Vec<Box<dyn GitStat>> = vec![Box::new(CountingStat { authors_seen: authors_seen.clone() })];
+
+        let processed = process_commit(&commit, &mut collectors, Some(&range), None);
+
+        assert!(!processed);
+        assert!(authors_seen.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_commit_inside_range_reaches_collectors() {
+        let range = CommitRange::new(Some("2024-01-01"), None).unwrap();
+        let mut commit = GitCommit::default();
+        commit.date = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+
+        let authors_seen = Rc::new(RefCell::new(Vec::new()));
+        let mut collectors: Vec<Box<dyn GitStat>> = vec![Box::new(CountingStat { authors_seen: authors_seen.clone() })];
+
+        let processed = process_commit(&commit, &mut collectors, Some(&range), None);
+
+        assert!(processed);
+        assert_eq!(authors_seen.borrow().len(), 1);
+    }
+
+    #[test]
+    fn test_mailmap_normalizes_author_before_collectors_see_it() {
+        let mailmap = Mailmap::from_str("Jane Doe <jane@work> <jane@personal>");
+        let mut commit = GitCommit::default();
+        commit.author = "Jane Doe <jane@personal>".to_string();
+
+        let authors_seen = Rc::new(RefCell::new(Vec::new()));
+        let mut collectors: Vec<Box<dyn GitStat>> = vec![Box::new(CountingStat { authors_seen: authors_seen.clone() })];
+
+        process_commit(&commit, &mut collectors, None, Some(&mailmap));
+
+        assert_eq!(*authors_seen.borrow(), vec!["Jane Doe <jane@work>".to_string()]);
+    }
+}
@@ -0,0 +1,178 @@
+use std::io;
+
+use crate::heatmap::ColorScheme;
+use crate::viewmodel::GitStatsViewModel;
+
+/// Renders a finished `GitStatsViewModel` into an output stream. Implemented
+/// by `HtmlReporter`; a JSON reporter would sit alongside it the same way
+/// `stats::JsonValue` lets individual collectors opt into JSON export.
+pub trait Reporter {
+    fn render(&self, viewmodel: &GitStatsViewModel, output: &mut dyn io::Write) -> io::Result<()>;
+}
+
+/// Renders a `GitStatsViewModel` as HTML: the summary, estimated hours,
+/// co-changing file pairs, contribution heatmap and code-size series, in
+/// that order. The commits/lines/files-by-day series and the punch card
+/// data are not rendered.
+#[derive(Debug, Clone, Default)]
+pub struct HtmlReporter {}
+
+impl HtmlReporter {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Reporter for HtmlReporter {
+    fn render(&self, viewmodel: &GitStatsViewModel, output: &mut dyn io::Write) -> io::Result<()> {
+        render_summary(viewmodel, output)?;
+        render_estimated_hours(viewmodel, output)?;
+        render_similar_files(viewmodel, output)?;
+        render_heatmap(viewmodel, output)?;
+        render_code_size(viewmodel, output)
+    }
+}
+
+fn render_summary(viewmodel: &GitStatsViewModel, output: &mut dyn io::Write) -> io::Result<()> {
+    writeln!(output, "<table>")?;
+    for entry in &viewmodel.summary {
+        writeln!(
+            output,
+            "<tr>\n    <td>{}</td>\n    <td>{}</td>\n</tr>",
+            entry.name, entry.value
+        )?;
+    }
+    writeln!(output, "</table>")
+}
+
+/// Renders `viewmodel.estimated_hours_by_author`, one row per author,
+/// sorted the same way `EstimatedHoursCollector` already sorted them.
+fn render_estimated_hours(viewmodel: &GitStatsViewModel, output: &mut dyn io::Write) -> io::Result<()> {
+    if viewmodel.estimated_hours_by_author.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(output, "<table>")?;
+    writeln!(output, "<tr>\n    <th>Author</th>\n    <th>Estimated hours</th>\n</tr>")?;
+    for entry in &viewmodel.estimated_hours_by_author {
+        writeln!(
+            output,
+            "<tr>\n    <td>{}</td>\n    <td>{:.1}</td>\n</tr>",
+            entry.author, entry.hours
+        )?;
+    }
+    writeln!(output, "</table>")
+}
+
+/// Renders `viewmodel.similar_files`, the file pairs
+/// `SimilarFilesChangingCollector` found tend to change together, as a
+/// table of the two files alongside their co-change count and confidence.
+fn render_similar_files(viewmodel: &GitStatsViewModel, output: &mut dyn io::Write) -> io::Result<()> {
+    if viewmodel.similar_files.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(output, "<table>")?;
+    writeln!(
+        output,
+        "<tr>\n    <th>File A</th>\n    <th>File B</th>\n    <th>Co-changes</th>\n    <th>Confidence</th>\n</tr>"
+    )?;
+    for pair in &viewmodel.similar_files {
+        writeln!(
+            output,
+            "<tr>\n    <td>{}</td>\n    <td>{}</td>\n    <td>{}</td>\n    <td>{:.0}%</td>\n</tr>",
+            pair.file_a, pair.file_b, pair.co_changes, pair.confidence * 100.0
+        )?;
+    }
+    writeln!(output, "</table>")
+}
+
+/// Renders `viewmodel.heatmap` as one `<div>` per day that has commits, in
+/// date order, each tagged with its (Monday-based) weekday and ISO week in
+/// `data-` attributes and its intensity level in a class, inside a wrapper
+/// classed per `viewmodel.heatmap_color_scheme`. Days without commits get
+/// no cell, and no layout styles are emitted.
+fn render_heatmap(viewmodel: &GitStatsViewModel, output: &mut dyn io::Write) -> io::Result<()> {
+    if viewmodel.heatmap.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(
+        output,
+        "<div class=\"heatmap heatmap-{}\">",
+        color_scheme_class(viewmodel.heatmap_color_scheme)
+    )?;
+    for day in &viewmodel.heatmap {
+        writeln!(
+            output,
+            "<div class=\"heatmap-cell heatmap-cell-level-{}\" data-weekday=\"{}\" data-iso-week=\"{}-{:02}\" title=\"{}: {} commits\"></div>",
+            day.intensity, day.weekday, day.iso_year, day.iso_week, day.date, day.commit_count
+        )?;
+    }
+    writeln!(output, "</div>")
+}
+
+fn color_scheme_class(scheme: ColorScheme) -> &'static str {
+    match scheme {
+        ColorScheme::Green => "green",
+        ColorScheme::Blue => "blue",
+    }
+}
+
+/// Renders `viewmodel.code_size_over_time` as a running-total table plus a
+/// list of the commits `CodeSizeCollector` flagged as notable jumps, so a
+/// sudden bloat or mass-deletion stands out without external tooling.
+fn render_code_size(viewmodel: &GitStatsViewModel, output: &mut dyn io::Write) -> io::Result<()> {
+    if viewmodel.code_size_over_time.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(output, "<table>")?;
+    writeln!(output, "<tr>\n    <th>Commit</th>\n    <th>Date</th>\n    <th>Total lines</th>\n</tr>")?;
+    for point in &viewmodel.code_size_over_time {
+        writeln!(
+            output,
+            "<tr>\n    <td>{}</td>\n    <td>{}</td>\n    <td>{}</td>\n</tr>",
+            point.commit_hash, point.date, point.total_lines
+        )?;
+    }
+    writeln!(output, "</table>")?;
+
+    let notable: Vec<_> = viewmodel.code_size_over_time.iter().filter(|point| point.notable).collect();
+    if notable.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(output, "<ul class=\"notable-commits\">")?;
+    for point in notable {
+        writeln!(output, "<li>{} ({}): {:+} lines</li>", point.commit_hash, point.date, point.delta)?;
+    }
+    writeln!(output, "</ul>")
+}
+
+/// An in-memory `io::Write` sink, so tests and callers that don't want to
+/// touch the filesystem can capture a report and inspect it as a string.
+#[derive(Debug, Clone, Default)]
+pub struct BufferedOutput {
+    buffer: Vec<u8>,
+}
+
+impl BufferedOutput {
+    pub fn new() -> Self {
+        Self { buffer: Vec::new() }
+    }
+
+    pub fn to_string(&self) -> String {
+        String::from_utf8_lossy(&self.buffer).into_owned()
+    }
+}
+
+impl io::Write for BufferedOutput {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buffer.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
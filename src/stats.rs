@@ -0,0 +1,50 @@
+use serde_json::Error;
+
+use crate::viewmodel::GitStatsJsonViewModelItem;
+
+/// Running totals `SummaryStatsCollector` accumulates across the whole
+/// commit range.
+#[derive(Debug, Clone, Default)]
+pub struct SummaryStats {
+    pub commit_count: i32,
+    pub date_first_commit: String,
+    pub first_committer: String,
+    pub total_lines_added: i32,
+    pub total_lines_deleted: i32,
+}
+
+/// Per-day file-operation counts, keyed externally by `commit.day_key()`.
+#[derive(Debug, Clone, Default)]
+pub struct FileStats {
+    pub added: i32,
+    pub deleted: i32,
+    pub modified: i32,
+    pub renamed: i32,
+}
+
+/// Running totals `MessageStatsCollector` accumulates across commit
+/// messages.
+#[derive(Debug, Clone, Default)]
+pub struct MessageStats {
+    pub max_size: i32,
+    pub max_lines: i32,
+    pub min_size: i32,
+    pub min_lines: i32,
+    pub avg_size: i32,
+    pub avg_lines: i32,
+}
+
+/// One weekday/hour bucket of `PunchCardCollector`'s punch card.
+#[derive(Debug, Clone)]
+pub struct PunchStats {
+    pub weekday: u32,
+    pub hour: u32,
+    pub commits: i32,
+}
+
+/// Implemented by collectors that can also render themselves as a
+/// self-contained JSON view model entry (e.g. for a JSON export alongside
+/// `HtmlReporter`'s HTML tables).
+pub trait JsonValue {
+    fn get_json_viewmodel(&self) -> Result<GitStatsJsonViewModelItem, Error>;
+}
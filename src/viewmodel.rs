@@ -0,0 +1,90 @@
+use crate::heatmap::{ColorScheme, HeatmapDay};
+
+/// Everything the collectors from `create_stat_collectors` contribute to,
+/// and the only thing `HtmlReporter` (or any other reporter) reads from.
+#[derive(Debug, Clone, Default)]
+pub struct GitStatsViewModel {
+    pub summary: Vec<SummaryViewModel>,
+    pub total_commits_by_day: Vec<KeyValue>,
+    pub total_lines_by_day: Vec<LinesValue>,
+    pub total_files_by_day: Vec<FilesValue>,
+    pub punch_data: Vec<PunchesValue>,
+    pub estimated_hours_by_author: Vec<HoursValue>,
+    pub similar_files: Vec<FilePairValue>,
+    pub heatmap: Vec<HeatmapDay>,
+    /// Color scheme `CommitHeatmapCollector` was configured with, for
+    /// `HtmlReporter` to paint the `heatmap` grid with.
+    pub heatmap_color_scheme: ColorScheme,
+    pub code_size_over_time: Vec<CodeSizePoint>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SummaryViewModel {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct KeyValue {
+    pub key: String,
+    pub value: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct LinesValue {
+    pub key: String,
+    pub lines_added: i32,
+    pub lines_deleted: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct FilesValue {
+    pub key: String,
+    pub files_added: i32,
+    pub files_deleted: i32,
+    pub files_modified: i32,
+    pub files_renamed: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct PunchesValue {
+    pub weekday: u32,
+    pub hour: u32,
+    pub commits: i32,
+}
+
+/// One author's estimated time invested, from `EstimatedHoursCollector`.
+#[derive(Debug, Clone)]
+pub struct HoursValue {
+    pub author: String,
+    pub hours: f64,
+}
+
+/// One file pair that tends to change together, from
+/// `SimilarFilesChangingCollector`.
+#[derive(Debug, Clone)]
+pub struct FilePairValue {
+    pub file_a: String,
+    pub file_b: String,
+    pub co_changes: i32,
+    pub confidence: f64,
+}
+
+/// One point on `CodeSizeCollector`'s running-total time series.
+#[derive(Debug, Clone)]
+pub struct CodeSizePoint {
+    pub commit_hash: String,
+    pub date: String,
+    pub delta: i64,
+    pub total_lines: i64,
+    pub notable: bool,
+}
+
+/// A self-contained JSON view model entry, as produced by
+/// `crate::stats::JsonValue::get_json_viewmodel`.
+#[derive(Debug, Clone)]
+pub struct GitStatsJsonViewModelItem {
+    pub summary: Vec<SummaryViewModel>,
+    pub key: String,
+    pub data: serde_json::Value,
+}
@@ -1,4 +1,4 @@
-use forora::{BufferedOutput, HtmlReporter};
+use forora::{BufferedOutput, HtmlReporter, RunFororaOptions};
 
 mod common;
 
@@ -8,7 +8,7 @@ fn test_cli_report() {
     common::setup_git_repo(|path| {
         let mut output = BufferedOutput::new();
         let reporter = HtmlReporter::new();
-        forora::run_forora(path, &mut output, Box::new(reporter))?;
+        forora::run_forora(path, &mut output, Box::new(reporter), &RunFororaOptions::default())?;
         println!("OUTPUT: {}", output.to_string());
         assert!(output.to_string().contains("<tr>
                     <td>Number of commits</td>